@@ -0,0 +1,52 @@
+//! Bridges arbitrary waitable kernel objects onto a handle-based completion
+//! path.
+//!
+//! This is the counterpart to the socket registration path driven by
+//! [`IoSource`]. Sockets reach the selector through AFD; a plain `HANDLE`
+//! (a file, a named pipe, ...) cannot. Rather than blindly associating the
+//! handle with the shared socket completion port -- whose packets the selector
+//! interprets as its own per-operation `OVERLAPPED` state -- registration is
+//! delegated to the selector's dedicated handle path, which tags and validates
+//! its own completion packets before translating them into [`event::Event`]s.
+//!
+//! [`IoSource`]: crate::io_source::IoSource
+//! [`event::Event`]: crate::event::Event
+
+use crate::{Interest, Registry, Token};
+
+use std::io;
+use std::os::windows::io::RawHandle;
+
+/// Register `handle` on the selector's dedicated handle-completion path.
+pub(crate) fn register(
+    registry: &Registry,
+    handle: RawHandle,
+    token: Token,
+    interests: Interest,
+) -> io::Result<()> {
+    registry.selector().register_handle(handle, token, interests)
+}
+
+/// Re-registering a handle is not supported.
+///
+/// Once a handle is bound to a completion port it cannot be rebound, and its
+/// completion key cannot be changed, so there is no way to move it to a new
+/// token. Deregister and register a fresh [`SourceHandle`] instead.
+///
+/// [`SourceHandle`]: super::SourceHandle
+pub(crate) fn reregister(
+    _registry: &Registry,
+    _handle: RawHandle,
+    _token: Token,
+    _interests: Interest,
+) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "a Windows HANDLE cannot be re-registered once bound to the completion port",
+    ))
+}
+
+/// Drop the selector's interest in `handle`.
+pub(crate) fn deregister(registry: &Registry, handle: RawHandle) -> io::Result<()> {
+    registry.selector().deregister_handle(handle)
+}
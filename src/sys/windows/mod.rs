@@ -0,0 +1,7 @@
+mod handle;
+
+mod source_handle;
+pub use self::source_handle::SourceHandle;
+
+mod source_socket;
+pub use self::source_socket::{BorrowedSourceSocket, OwnedSourceSocket, SourceSocket};
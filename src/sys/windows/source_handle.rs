@@ -0,0 +1,92 @@
+use crate::sys::windows::handle;
+use crate::{event, Interest, Registry, Token};
+
+use std::io;
+use std::os::windows::io::RawHandle;
+
+/// Adapter for [`RawHandle`] providing an [`event::Source`] implementation.
+///
+/// Where [`SourceSocket`] bridges sockets onto the AFD/IOCP socket path,
+/// `SourceHandle` bridges arbitrary waitable kernel objects -- file handles,
+/// named pipes, and other `HANDLE`s -- by handing them to the selector's
+/// dedicated handle-completion path, so they can be registered with [`Poll`]
+/// through the same `register`/`reregister`/`deregister` API.
+///
+/// `SourceHandle` borrows a `&RawHandle` and, like the kernel object it points
+/// at, does nothing to keep that handle open: the handle must stay valid for as
+/// long as it is registered, and closing it is how you stop its completions
+/// from arriving (see [`deregister`]).
+///
+/// Note that, unlike sockets, a handle cannot be re-registered: once it is bound
+/// to the completion port its completion key is fixed, so [`reregister`] returns
+/// an [`io::ErrorKind::Unsupported`] error. Deregister and register again to
+/// change the token or interests.
+///
+/// [`event::Source`]: ../event/trait.Source.html
+/// [`SourceSocket`]: struct.SourceSocket.html
+/// [`Poll`]: ../struct.Poll.html
+/// [`deregister`]: ../struct.Registry.html#method.deregister
+/// [`reregister`]: ../struct.Registry.html#method.reregister
+/// [`io::ErrorKind::Unsupported`]: std::io::ErrorKind::Unsupported
+///
+/// # Examples
+///
+/// Implementing [`event::Source`] for a custom type backed by a [`RawHandle`].
+///
+#[cfg_attr(all(feature = "os-poll", feature = "os-ext"), doc = "```")]
+#[cfg_attr(not(all(feature = "os-poll", feature = "os-ext")), doc = "```ignore")]
+/// use mio::{event, Interest, Registry, Token};
+/// use mio::windows::SourceHandle;
+///
+/// use std::os::windows::io::RawHandle;
+/// use std::io;
+///
+/// # #[allow(dead_code)]
+/// pub struct MyIo {
+///     handle: RawHandle,
+/// }
+///
+/// impl event::Source for MyIo {
+///     fn register(&mut self, registry: &Registry, token: Token, interests: Interest)
+///         -> io::Result<()>
+///     {
+///         SourceHandle(&self.handle).register(registry, token, interests)
+///     }
+///
+///     fn reregister(&mut self, registry: &Registry, token: Token, interests: Interest)
+///         -> io::Result<()>
+///     {
+///         SourceHandle(&self.handle).reregister(registry, token, interests)
+///     }
+///
+///     fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+///         SourceHandle(&self.handle).deregister(registry)
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SourceHandle<'a>(pub &'a RawHandle);
+
+impl<'a> event::Source for SourceHandle<'a> {
+    fn register(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        handle::register(registry, *self.0, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        handle::reregister(registry, *self.0, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        handle::deregister(registry, *self.0)
+    }
+}
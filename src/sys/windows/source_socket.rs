@@ -2,7 +2,7 @@ use crate::{event, Interest, Registry, Token};
 use crate::io_source::IoSource;
 
 use std::io;
-use std::os::windows::io::RawSocket;
+use std::os::windows::io::{AsRawSocket, BorrowedSocket, OwnedSocket, RawSocket};
 
 /// Adapter for [`RawSocket`] providing an [`event::Source`] implementation.
 ///
@@ -99,7 +99,7 @@ impl<'a> event::Source for SourceSocket<'a> {
         token: Token,
         interests: Interest,
     ) -> io::Result<()> {
-        IoSource::new(self.0).register(registry, token, interests)
+        io_source(self.0).register(registry, token, interests)
     }
 
     fn reregister(
@@ -108,10 +108,154 @@ impl<'a> event::Source for SourceSocket<'a> {
         token: Token,
         interests: Interest,
     ) -> io::Result<()> {
-        IoSource::new(self.0).reregister(registry, token, interests)
+        io_source(self.0).reregister(registry, token, interests)
     }
 
     fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
-        IoSource::new(self.0).deregister(registry)
+        io_source(self.0).deregister(registry)
+    }
+}
+
+/// Build an [`IoSource`] for a socket identified by its raw handle. This is the
+/// single funnel every socket adapter in this module uses to reach the selector.
+fn io_source(socket: &RawSocket) -> IoSource<&RawSocket> {
+    IoSource::new(socket)
+}
+
+/// Adapter for [`BorrowedSocket`] providing an [`event::Source`] implementation.
+///
+/// Unlike [`SourceSocket`], which borrows a bare [`RawSocket`] and relies on the
+/// caller to keep the socket alive for the duration of the registration, this
+/// adapter wraps a [`BorrowedSocket`]. The borrow checker then guarantees the
+/// socket outlives the adapter, so it no longer has to be constructed right
+/// before [`Registry::register`]. It does not tie the registration itself to
+/// the borrow: the registration stays in place after the adapter is dropped,
+/// and the socket can then be closed while still registered.
+///
+/// [`event::Source`]: ../event/trait.Source.html
+/// [`Registry::register`]: ../struct.Registry.html#method.register
+///
+/// # Examples
+///
+#[cfg_attr(
+    all(feature = "os-poll", feature = "net", feature = "os-ext"),
+    doc = "```"
+)]
+#[cfg_attr(
+    not(all(feature = "os-poll", feature = "net", feature = "os-ext")),
+    doc = "```ignore"
+)]
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use mio::{Interest, Poll, Token};
+/// use mio::windows::BorrowedSourceSocket;
+///
+/// use std::os::windows::io::AsSocket;
+/// use std::net::TcpListener;
+///
+/// let listener = TcpListener::bind("127.0.0.1:0")?;
+///
+/// let poll = Poll::new()?;
+///
+/// // The borrow is tied to `listener`, so it cannot outlive the socket.
+/// poll.registry().register(
+///     &mut BorrowedSourceSocket(listener.as_socket()),
+///     Token(0),
+///     Interest::READABLE)?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BorrowedSourceSocket<'a>(pub BorrowedSocket<'a>);
+
+impl<'a> event::Source for BorrowedSourceSocket<'a> {
+    fn register(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        io_source(&self.0.as_raw_socket()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        io_source(&self.0.as_raw_socket()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        io_source(&self.0.as_raw_socket()).deregister(registry)
+    }
+}
+
+/// Adapter for [`OwnedSocket`] providing an [`event::Source`] implementation.
+///
+/// This adapter takes ownership of the socket and closes it on drop, so the
+/// registration and the socket's lifecycle are managed together. Use it when
+/// you want a safe, non-footgun path for registering an arbitrary socket with
+/// [`Poll`] without separately tracking when to close the underlying handle.
+///
+/// [`event::Source`]: ../event/trait.Source.html
+/// [`Poll`]: ../struct.Poll.html
+///
+/// # Examples
+///
+#[cfg_attr(
+    all(feature = "os-poll", feature = "net", feature = "os-ext"),
+    doc = "```"
+)]
+#[cfg_attr(
+    not(all(feature = "os-poll", feature = "net", feature = "os-ext")),
+    doc = "```ignore"
+)]
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use mio::{Interest, Poll, Token};
+/// use mio::windows::OwnedSourceSocket;
+///
+/// use std::os::windows::io::{FromRawSocket, IntoRawSocket, OwnedSocket};
+/// use std::net::TcpListener;
+///
+/// let listener = TcpListener::bind("127.0.0.1:0")?;
+/// // Hand the socket over; the adapter will close it on drop.
+/// let socket = unsafe { OwnedSocket::from_raw_socket(listener.into_raw_socket()) };
+///
+/// let poll = Poll::new()?;
+///
+/// poll.registry().register(
+///     &mut OwnedSourceSocket(socket),
+///     Token(0),
+///     Interest::READABLE)?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct OwnedSourceSocket(pub OwnedSocket);
+
+impl event::Source for OwnedSourceSocket {
+    fn register(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        io_source(&self.0.as_raw_socket()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        io_source(&self.0.as_raw_socket()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        io_source(&self.0.as_raw_socket()).deregister(registry)
     }
 }